@@ -1,5 +1,33 @@
-use actix_web::{web, App, HttpResponse, HttpServer, Result};
+use actix_web::{dev::Server, http::header, middleware, web, App, HttpResponse, HttpServer, Result};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::net::TcpListener;
+
+/// Shared state handed to every worker via `web::Data`. Holds a single
+/// `reqwest::Client` so outbound connections are pooled and reused across
+/// requests instead of being re-established per handler call.
+struct AppState {
+    http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct EchoRequest {
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EchoResponse {
+    message: String,
+    length: usize,
+}
+
+async fn echo(payload: web::Json<EchoRequest>) -> Result<HttpResponse> {
+    let EchoRequest { message } = payload.into_inner();
+    Ok(HttpResponse::Ok().json(EchoResponse {
+        length: message.len(),
+        message,
+    }))
+}
 
 async fn index() -> Result<HttpResponse> {
     Ok(HttpResponse::Ok().json(json!({
@@ -7,24 +35,82 @@ async fn index() -> Result<HttpResponse> {
     })))
 }
 
+/// Demonstrates the shared pooled client: proxies to an upstream service
+/// using the `reqwest::Client` stored in `AppState` instead of creating a
+/// new client per request. Kept off the `/` route so a freshly generated
+/// project's landing page works with no network access (offline dev, CI,
+/// sandboxed containers).
+async fn proxy(data: web::Data<AppState>) -> Result<HttpResponse> {
+    let upstream: serde_json::Value = data
+        .http_client
+        .get("https://httpbin.org/ip")
+        .send()
+        .await
+        .and_then(|res| res.error_for_status())
+        .map_err(actix_web::error::ErrorBadGateway)?
+        .json()
+        .await
+        .map_err(actix_web::error::ErrorBadGateway)?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "upstream": upstream
+    })))
+}
+
 async fn health() -> Result<HttpResponse> {
     Ok(HttpResponse::Ok().json(json!({
         "status": "healthy"
     })))
 }
 
-#[actix_web::main]
-async fn main() -> std::io::Result<()> {
-    env_logger::init();
-    
-    println!("Starting {{project_name}} server...");
-    
-    HttpServer::new(|| {
+async fn get_user(path: web::Path<u32>) -> Result<HttpResponse> {
+    let user_id = path.into_inner();
+    Ok(HttpResponse::Ok().json(json!({
+        "user_id": user_id
+    })))
+}
+
+/// Builds the `HttpServer` on an already-bound listener and returns it
+/// without awaiting. Keeping this separate from `main` lets integration
+/// tests bind to an OS-assigned port (`TcpListener::bind("127.0.0.1:0")`),
+/// spawn the returned server, and drive it over real HTTP.
+pub fn run(listener: TcpListener) -> std::io::Result<Server> {
+    let app_state = web::Data::new(AppState {
+        http_client: reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .expect("failed to build reqwest client"),
+    });
+
+    let server = HttpServer::new(move || {
         App::new()
+            .app_data(app_state.clone())
+            .app_data(web::JsonConfig::default().limit(4096))
+            .wrap(middleware::Logger::default())
+            .wrap(middleware::Compress::default())
+            .wrap(middleware::DefaultHeaders::new().add((header::SERVER, "{{project_name}}/1.0")))
             .route("/", web::get().to(index))
             .route("/health", web::get().to(health))
+            .route("/echo", web::post().to(echo))
+            .route("/proxy", web::get().to(proxy))
+            .service(
+                web::resource("/users/{user_id}")
+                    .route(web::get().to(get_user))
+                    .default_service(web::route().to(HttpResponse::MethodNotAllowed)),
+            )
     })
-    .bind("0.0.0.0:8080")?
-    .run()
-    .await
+    .listen(listener)?
+    .run();
+
+    Ok(server)
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
+
+    println!("Starting {{project_name}} server...");
+
+    let listener = TcpListener::bind("0.0.0.0:8080")?;
+    run(listener)?.await
 }
@@ -0,0 +1,27 @@
+use axum::{routing::get, Json, Router};
+use serde_json::json;
+
+async fn index() -> Json<serde_json::Value> {
+    Json(json!({
+        "message": "Welcome to {{project_name}}"
+    }))
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(json!({
+        "status": "healthy"
+    }))
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    println!("Starting {{project_name}} server...");
+
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/health", get(health));
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+
+    axum::serve(listener, app).await
+}